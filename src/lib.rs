@@ -26,38 +26,199 @@ pub const fn always_true() -> bool {
     true
 }
 
+/// Decodes the scalar value starting at `s[index]`, returning `(chr,
+/// bytes_consumed)`.
+///
+/// This is the one UTF-8 decode step every length/encode function in this
+/// crate builds on, so a fix here (e.g. an overlong-encoding edge case)
+/// does not need to be replicated anywhere else.
+#[doc(hidden)]
+pub const fn decode_scalar(s: &[u8], index: usize) -> (u32, usize) {
+    if s[index] & 0x80 == 0x00 {
+        (s[index] as u32, 1)
+    } else if s[index] & 0xe0 == 0xc0 {
+        (
+            (s[index] as u32 & 0x1f) << 6 | (s[index + 1] as u32 & 0x3f),
+            2,
+        )
+    } else if s[index] & 0xf0 == 0xe0 {
+        (
+            (s[index] as u32 & 0x0f) << 12
+                | (s[index + 1] as u32 & 0x3f) << 6
+                | (s[index + 2] as u32 & 0x3f),
+            3,
+        )
+    } else if s[index] & 0xf8 == 0xf0 {
+        (
+            (s[index] as u32 & 0x07) << 18
+                | (s[index + 1] as u32 & 0x3f) << 12
+                | (s[index + 2] as u32 & 0x3f) << 6
+                | (s[index + 3] as u32 & 0x3f),
+            4,
+        )
+    } else {
+        ["Invalid literal provided."][(always_true() as usize)];
+        (0, 1)
+    }
+}
+
 #[doc(hidden)]
 pub const fn wide_len(s: &str) -> usize {
     let s = s.as_bytes();
     let mut length: usize = 0;
     let mut index: usize = 0;
     while index < s.len() {
-        let mut chr = 0;
-        if s[index] & 0x80 == 0x00 {
-            chr = s[index] as u32;
-            index += 1;
-        } else if s[index] & 0xe0 == 0xc0 {
-            chr = (s[index] as u32 & 0x1f) << 6 | (s[index + 1] as u32 & 0x3f);
-            index += 2;
-        } else if s[index] & 0xf0 == 0xe0 {
-            chr = (s[index] as u32 & 0x0f) << 12
-                | (s[index + 1] as u32 & 0x3f) << 6
-                | (s[index + 2] as u32 & 0x3f);
-            index += 3;
-        } else if s[index] & 0xf8 == 0xf0 {
-            chr = (s[index] as u32 & 0x07) << 18
-                | (s[index + 1] as u32 & 0x3f) << 12
-                | (s[index + 2] as u32 & 0x3f) << 6
-                | (s[index + 3] as u32 & 0x3f);
-            index += 4;
-        } else {
-            ["Invalid literal provided."][(always_true() as usize)];
-        };
+        let (chr, consumed) = decode_scalar(s, index);
+        index += consumed;
         length += [1, 2][(chr >= 0x10000) as usize];
     }
     length
 }
 
+/// A fixed-capacity buffer holding the UTF-16 encoding of a `&str`.
+///
+/// This is what [`encode_utf16`](encode_utf16) and
+/// [`encode_utf16_null`](encode_utf16_null) return, for composing wide
+/// strings from within your own `const fn`s rather than being limited to the
+/// macro form. `CAP` is the buffer's capacity in `u16` units; the buffer
+/// tracks how many of those units are actually in use.
+pub struct Utf16Buffer<const CAP: usize> {
+    data: [u16; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> Utf16Buffer<CAP> {
+    /// Returns the encoded `u16` units, excluding any unused capacity.
+    pub const fn as_slice(&self) -> &[u16] {
+        let (used, _) = self.data.split_at(self.len);
+        used
+    }
+
+    /// Returns the number of `u16` units actually written.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Encodes a `&str` as UTF-16 into a [`Utf16Buffer`](Utf16Buffer) of
+/// capacity `CAP`.
+///
+/// This is a compile-time error if `s` needs more than `CAP` units to
+/// encode. The `utf16!` macro uses this function internally, sized to fit
+/// exactly; call it directly when you need to encode a `&str` that arrives
+/// as a const-fn parameter deeper inside your own const code.
+///
+/// ```rust
+/// use utf16_lit::{encode_utf16, Utf16Buffer};
+///
+/// const BUFFER: Utf16Buffer<16> = encode_utf16("hi");
+///
+/// fn main() {
+///     assert_eq!(BUFFER.as_slice(), &[b'h' as u16, b'i' as u16]);
+///     assert_eq!(BUFFER.len(), 2);
+/// }
+/// ```
+///
+/// A string that needs more than `CAP` units is a compile-time error:
+///
+/// ```compile_fail
+/// use utf16_lit::{encode_utf16, Utf16Buffer};
+///
+/// const BUFFER: Utf16Buffer<1> = encode_utf16("hi");
+/// ```
+pub const fn encode_utf16<const CAP: usize>(s: &str) -> Utf16Buffer<CAP> {
+    let s = s.as_bytes();
+    let mut data = [0u16; CAP];
+    let mut char_index: usize = 0;
+    let mut data_index: usize = 0;
+    while char_index < s.len() {
+        let (chr, consumed) = decode_scalar(s, char_index);
+        char_index += consumed;
+        if chr >= 0x10000 {
+            if data_index + 2 > CAP {
+                ["String exceeds Utf16Buffer capacity."][(always_true() as usize)];
+            }
+            data[data_index] = (0xD800 + (chr - 0x10000) / 0x400) as u16;
+            data[data_index + 1] = (0xDC00 + (chr - 0x10000) % 0x400) as u16;
+            data_index += 2;
+        } else {
+            if data_index + 1 > CAP {
+                ["String exceeds Utf16Buffer capacity."][(always_true() as usize)];
+            }
+            data[data_index] = chr as u16;
+            data_index += 1;
+        }
+    }
+    Utf16Buffer {
+        data,
+        len: data_index,
+    }
+}
+
+/// Like [`encode_utf16`](encode_utf16), but appends a trailing `0` unit.
+///
+/// ```rust
+/// use utf16_lit::{encode_utf16_null, Utf16Buffer};
+///
+/// const BUFFER: Utf16Buffer<16> = encode_utf16_null("hi");
+///
+/// fn main() {
+///     assert_eq!(BUFFER.as_slice(), &[b'h' as u16, b'i' as u16, 0]);
+/// }
+/// ```
+pub const fn encode_utf16_null<const CAP: usize>(s: &str) -> Utf16Buffer<CAP> {
+    let mut buffer: Utf16Buffer<CAP> = encode_utf16(s);
+    if buffer.len + 1 > CAP {
+        ["String exceeds Utf16Buffer capacity."][(always_true() as usize)];
+    }
+    buffer.data[buffer.len] = 0;
+    buffer.len += 1;
+    buffer
+}
+
+#[doc(hidden)]
+pub const fn check_no_interior_nul<const CAP: usize>(s: &str) {
+    let buffer: Utf16Buffer<CAP> = encode_utf16(s);
+    let units = buffer.as_slice();
+    let mut i = 0;
+    while i < units.len() {
+        if units[i] == 0 {
+            ["interior NUL in C string literal"][(always_true() as usize)];
+        }
+        i += 1;
+    }
+}
+
+#[doc(hidden)]
+pub const fn wide_len_ucs2(s: &str) -> usize {
+    let s = s.as_bytes();
+    let mut length: usize = 0;
+    let mut index: usize = 0;
+    while index < s.len() {
+        let (chr, consumed) = decode_scalar(s, index);
+        index += consumed;
+        if chr >= 0x10000 {
+            ["Scalar value requires a surrogate pair; not representable in UCS-2."]
+                [(always_true() as usize)];
+        }
+        length += 1;
+    }
+    length
+}
+
+#[doc(hidden)]
+pub const fn wide_len_utf32(s: &str) -> usize {
+    let s = s.as_bytes();
+    let mut length: usize = 0;
+    let mut index: usize = 0;
+    while index < s.len() {
+        let (_chr, consumed) = decode_scalar(s, index);
+        index += consumed;
+        length += 1;
+    }
+    length
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! length {
@@ -66,6 +227,22 @@ macro_rules! length {
     }};
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! length_utf32 {
+    ($arg:expr) => {{
+        $crate::wide_len_utf32($arg)
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! length_ucs2 {
+    ($arg:expr) => {{
+        $crate::wide_len_ucs2($arg)
+    }};
+}
+
 /// Turns a string literal into a `[u16]` literal.
 ///
 /// If you want to have a "null terminated" string (such as for some parts of
@@ -75,6 +252,112 @@ macro_rules! length {
 macro_rules! utf16 {
     ($arg:expr) => {{
         const ARRAY_LENGTH: usize = $crate::length!($arg);
+        const BUFFER: $crate::Utf16Buffer<ARRAY_LENGTH> = $crate::encode_utf16($arg);
+        const RESULT: [u16; ARRAY_LENGTH] = {
+            let units = BUFFER.as_slice();
+            let mut data = [0u16; ARRAY_LENGTH];
+            let mut i = 0;
+            while i < ARRAY_LENGTH {
+                data[i] = units[i];
+                i += 1;
+            }
+            data
+        };
+        RESULT
+    }};
+}
+
+/// Turns a string literal into a `[u16]` literal with a null on the end.
+///
+/// If you do **not** want to have a null terminator added to the string then
+/// you should use [`utf16!`](utf16!).
+#[macro_export]
+macro_rules! utf16_null {
+    ($arg:expr) => {{
+        const ARRAY_LENGTH: usize = $crate::length!($arg) + 1;
+        const BUFFER: $crate::Utf16Buffer<ARRAY_LENGTH> = $crate::encode_utf16_null($arg);
+        const RESULT: [u16; ARRAY_LENGTH] = {
+            let units = BUFFER.as_slice();
+            let mut data = [0u16; ARRAY_LENGTH];
+            let mut i = 0;
+            while i < ARRAY_LENGTH {
+                data[i] = units[i];
+                i += 1;
+            }
+            data
+        };
+        RESULT
+    }};
+}
+
+/// Turns a string literal into a null-terminated `[u16]` literal, rejecting
+/// interior NUL units at compile time.
+///
+/// This is like [`utf16_null!`](utf16_null!), except a literal such as
+/// `"a\0b"` is a compile error instead of silently producing a buffer that C
+/// FFI would truncate at the first NUL. Use this when the result must be a
+/// genuine C string, with the appended terminator as the only NUL.
+///
+/// ```rust
+/// use utf16_lit::utf16_c;
+///
+/// const EXAMPLE: [u16; 3] = utf16_c!("hi");
+///
+/// fn main() {
+///     assert_eq!(EXAMPLE, [b'h' as u16, b'i' as u16, 0]);
+/// }
+/// ```
+///
+/// A literal with an interior NUL is a compile-time error:
+///
+/// ```compile_fail
+/// use utf16_lit::utf16_c;
+///
+/// const EXAMPLE: [u16; 4] = utf16_c!("a\0b");
+/// ```
+#[macro_export]
+macro_rules! utf16_c {
+    ($arg:expr) => {{
+        const ARRAY_LENGTH: usize = $crate::length!($arg);
+        const _: () = $crate::check_no_interior_nul::<ARRAY_LENGTH>($arg);
+        $crate::utf16_null!($arg)
+    }};
+}
+
+/// Turns a string literal into a `[u16]` literal, restricted to the Basic
+/// Multilingual Plane (UCS-2).
+///
+/// UEFI's text protocols and other firmware interfaces expect UCS-2 and
+/// choke on surrogate pairs, so this is a compile error whenever a decoded
+/// scalar value would require one (i.e. is `>= 0x10000`), statically
+/// proving the literal is representable in a single 16-bit unit per
+/// character.
+///
+/// If you want to have a "null terminated" string then you should use
+/// [`ucs2_null!`](ucs2_null!).
+///
+/// ```rust
+/// use utf16_lit::ucs2;
+///
+/// const EXAMPLE: [u16; 2] = ucs2!("hi");
+///
+/// fn main() {
+///     assert_eq!(EXAMPLE, [b'h' as u16, b'i' as u16]);
+/// }
+/// ```
+///
+/// Characters outside the Basic Multilingual Plane are a compile-time
+/// error:
+///
+/// ```compile_fail
+/// use utf16_lit::ucs2;
+///
+/// const EXAMPLE: [u16; 1] = ucs2!("😀");
+/// ```
+#[macro_export]
+macro_rules! ucs2 {
+    ($arg:expr) => {{
+        const ARRAY_LENGTH: usize = $crate::length_ucs2!($arg);
         const RESULT: [u16; ARRAY_LENGTH] = {
             pub const fn wide(s: &str) -> [u16; ARRAY_LENGTH] {
                 let s = s.as_bytes();
@@ -82,36 +365,14 @@ macro_rules! utf16 {
                 let mut char_index: usize = 0;
                 let mut data_index: usize = 0;
                 while char_index < s.len() {
-                    let mut chr = 0;
-                    if s[char_index] & 0x80 == 0x00 {
-                        chr = s[char_index] as u32;
-                        char_index += 1;
-                    } else if s[char_index] & 0xe0 == 0xc0 {
-                        chr =
-                            (s[char_index] as u32 & 0x1f) << 6 | (s[char_index + 1] as u32 & 0x3f);
-                        char_index += 2;
-                    } else if s[char_index] & 0xf0 == 0xe0 {
-                        chr = (s[char_index] as u32 & 0x0f) << 12
-                            | (s[char_index + 1] as u32 & 0x3f) << 6
-                            | (s[char_index + 2] as u32 & 0x3f);
-                        char_index += 3;
-                    } else if s[char_index] & 0xf8 == 0xf0 {
-                        chr = (s[char_index] as u32 & 0x07) << 18
-                            | (s[char_index + 1] as u32 & 0x3f) << 12
-                            | (s[char_index + 2] as u32 & 0x3f) << 6
-                            | (s[char_index + 3] as u32 & 0x3f);
-                        char_index += 4;
-                    } else {
-                        ["Invalid literal provided."][($crate::always_true() as usize)];
-                    };
+                    let (chr, consumed) = $crate::decode_scalar(s, char_index);
+                    char_index += consumed;
                     if chr >= 0x10000 {
-                        data[data_index] = (0xD800 + (chr - 0x10000) / 0x400) as u16;
-                        data[data_index + 1] = (0xDC00 + (chr - 0x10000) % 0x400) as u16;
-                        data_index += 2;
-                    } else {
-                        data[data_index] = chr as u16;
-                        data_index += 1;
+                        ["Scalar value requires a surrogate pair; not representable in UCS-2."]
+                            [($crate::always_true() as usize)];
                     }
+                    data[data_index] = chr as u16;
+                    data_index += 1;
                 }
                 data
             }
@@ -121,14 +382,285 @@ macro_rules! utf16 {
     }};
 }
 
-/// Turns a string literal into a `[u16]` literal with a null on the end.
+/// Turns a string literal into a `[u16]` literal with a null on the end,
+/// restricted to the Basic Multilingual Plane (UCS-2).
 ///
 /// If you do **not** want to have a null terminator added to the string then
-/// you should use [`utf16!`](utf16!).
+/// you should use [`ucs2!`](ucs2!).
+///
+/// ```rust
+/// use utf16_lit::ucs2_null;
+///
+/// const EXAMPLE: [u16; 3] = ucs2_null!("hi");
+///
+/// fn main() {
+///     assert_eq!(EXAMPLE, [b'h' as u16, b'i' as u16, 0]);
+/// }
+/// ```
 #[macro_export]
-macro_rules! utf16_null {
+macro_rules! ucs2_null {
     ($arg:expr) => {{
-        const U16: &[u16] = &$crate::utf16!($arg);
+        const U16: &[u16] = &$crate::ucs2!($arg);
+        const RESULT: [u16; U16.len() + 1] = {
+            let mut data = [0u16; U16.len() + 1];
+            let mut i = 0;
+            while i < data.len() - 1 {
+                data[i] = U16[i];
+                i += 1;
+            }
+            data
+        };
+        RESULT
+    }};
+}
+
+/// Turns a string literal into a `[u32]` literal (UTF-32 / UCS-4).
+///
+/// This is useful for interfacing with platforms where `wchar_t` is 4 bytes,
+/// such as most Unix-likes. Unlike [`utf16!`](utf16!), there is never any
+/// surrogate pairing: each decoded scalar value becomes exactly one `u32`.
+///
+/// If you want to have a "null terminated" string then you should use
+/// [`utf32_null!`](utf32_null!).
+///
+/// ```rust
+/// use utf16_lit::utf32;
+///
+/// const EXAMPLE: [u32; 7] = utf32!("example");
+///
+/// fn main() {
+///     let v: Vec<u32> = "example".chars().map(|c| c as u32).collect();
+///     assert_eq!(EXAMPLE.to_vec(), v);
+/// }
+/// ```
+#[macro_export]
+macro_rules! utf32 {
+    ($arg:expr) => {{
+        const ARRAY_LENGTH: usize = $crate::length_utf32!($arg);
+        const RESULT: [u32; ARRAY_LENGTH] = {
+            pub const fn wide(s: &str) -> [u32; ARRAY_LENGTH] {
+                let s = s.as_bytes();
+                let mut data = [0u32; ARRAY_LENGTH];
+                let mut char_index: usize = 0;
+                let mut data_index: usize = 0;
+                while char_index < s.len() {
+                    let (chr, consumed) = $crate::decode_scalar(s, char_index);
+                    char_index += consumed;
+                    data[data_index] = chr;
+                    data_index += 1;
+                }
+                data
+            }
+            wide($arg)
+        };
+        RESULT
+    }};
+}
+
+/// Turns a string literal into a `[u32]` literal with a null on the end.
+///
+/// If you do **not** want to have a null terminator added to the string then
+/// you should use [`utf32!`](utf32!).
+///
+/// ```rust
+/// use utf16_lit::utf32_null;
+///
+/// const EXAMPLE_NULL: [u32; 8] = utf32_null!("example");
+///
+/// fn main() {
+///     let mut v: Vec<u32> = "example".chars().map(|c| c as u32).collect();
+///     v.push(0);
+///     assert_eq!(EXAMPLE_NULL.to_vec(), v);
+/// }
+/// ```
+#[macro_export]
+macro_rules! utf32_null {
+    ($arg:expr) => {{
+        const U32: &[u32] = &$crate::utf32!($arg);
+        const RESULT: [u32; U32.len() + 1] = {
+            let mut data = [0u32; U32.len() + 1];
+            let mut i = 0;
+            while i < data.len() - 1 {
+                data[i] = U32[i];
+                i += 1;
+            }
+            data
+        };
+        RESULT
+    }};
+}
+
+/// Turns a string literal into a little-endian `[u8]` literal of its UTF-16
+/// encoding, for writing wide text into files or network buffers without a
+/// runtime transcode pass.
+///
+/// Pass `bom` as a second argument to prepend a little-endian byte order
+/// mark (`0xFF 0xFE`), e.g. `utf16_le_bytes!("hi", bom)`.
+///
+/// ```rust
+/// use utf16_lit::utf16_le_bytes;
+///
+/// const BYTES: [u8; 4] = utf16_le_bytes!("hi");
+/// const BYTES_BOM: [u8; 6] = utf16_le_bytes!("hi", bom);
+///
+/// fn main() {
+///     assert_eq!(BYTES, [b'h', 0, b'i', 0]);
+///     assert_eq!(&BYTES_BOM[..2], [0xFF, 0xFE]);
+///     assert_eq!(&BYTES_BOM[2..], BYTES);
+/// }
+/// ```
+#[macro_export]
+macro_rules! utf16_le_bytes {
+    ($arg:expr) => {
+        $crate::utf16_le_bytes!(@impl $arg, false)
+    };
+    ($arg:expr, bom) => {
+        $crate::utf16_le_bytes!(@impl $arg, true)
+    };
+    (@impl $arg:expr, $bom:expr) => {{
+        const UNITS: &[u16] = &$crate::utf16!($arg);
+        const HAS_BOM: bool = $bom;
+        const ARRAY_LENGTH: usize = UNITS.len() * 2 + if HAS_BOM { 2 } else { 0 };
+        const RESULT: [u8; ARRAY_LENGTH] = {
+            let mut data = [0u8; ARRAY_LENGTH];
+            let mut data_index: usize = 0;
+            if HAS_BOM {
+                data[0] = 0xFEFFu16 as u8;
+                data[1] = (0xFEFFu16 >> 8) as u8;
+                data_index = 2;
+            }
+            let mut i = 0;
+            while i < UNITS.len() {
+                let unit = UNITS[i];
+                data[data_index] = unit as u8;
+                data[data_index + 1] = (unit >> 8) as u8;
+                data_index += 2;
+                i += 1;
+            }
+            data
+        };
+        RESULT
+    }};
+}
+
+/// Turns a string literal into a big-endian `[u8]` literal of its UTF-16
+/// encoding, for writing wide text into files or network buffers without a
+/// runtime transcode pass.
+///
+/// Pass `bom` as a second argument to prepend a big-endian byte order mark
+/// (`0xFE 0xFF`), e.g. `utf16_be_bytes!("hi", bom)`.
+///
+/// ```rust
+/// use utf16_lit::utf16_be_bytes;
+///
+/// const BYTES: [u8; 4] = utf16_be_bytes!("hi");
+/// const BYTES_BOM: [u8; 6] = utf16_be_bytes!("hi", bom);
+///
+/// fn main() {
+///     assert_eq!(BYTES, [0, b'h', 0, b'i']);
+///     assert_eq!(&BYTES_BOM[..2], [0xFE, 0xFF]);
+///     assert_eq!(&BYTES_BOM[2..], BYTES);
+/// }
+/// ```
+#[macro_export]
+macro_rules! utf16_be_bytes {
+    ($arg:expr) => {
+        $crate::utf16_be_bytes!(@impl $arg, false)
+    };
+    ($arg:expr, bom) => {
+        $crate::utf16_be_bytes!(@impl $arg, true)
+    };
+    (@impl $arg:expr, $bom:expr) => {{
+        const UNITS: &[u16] = &$crate::utf16!($arg);
+        const HAS_BOM: bool = $bom;
+        const ARRAY_LENGTH: usize = UNITS.len() * 2 + if HAS_BOM { 2 } else { 0 };
+        const RESULT: [u8; ARRAY_LENGTH] = {
+            let mut data = [0u8; ARRAY_LENGTH];
+            let mut data_index: usize = 0;
+            if HAS_BOM {
+                data[0] = (0xFEFFu16 >> 8) as u8;
+                data[1] = 0xFEFFu16 as u8;
+                data_index = 2;
+            }
+            let mut i = 0;
+            while i < UNITS.len() {
+                let unit = UNITS[i];
+                data[data_index] = (unit >> 8) as u8;
+                data[data_index + 1] = unit as u8;
+                data_index += 2;
+                i += 1;
+            }
+            data
+        };
+        RESULT
+    }};
+}
+
+/// Joins several string expressions into a single `[u16]` literal, encoding
+/// each one and writing the resulting units sequentially into a shared
+/// buffer.
+///
+/// Useful for assembling message tables and multi-sz style blocks at
+/// compile time. The total length is the sum of each argument's encoded
+/// length; astral characters and surrogate pairs are handled consistently
+/// across segments because every segment goes through [`utf16!`](utf16!).
+///
+/// If you want a null terminator appended to the joined result then you
+/// should use [`utf16_null_concat!`](utf16_null_concat!).
+///
+/// ```rust
+/// use utf16_lit::utf16_concat;
+///
+/// const EXAMPLE: [u16; 6] = utf16_concat!("foo", "bar");
+///
+/// fn main() {
+///     let v: Vec<u16> = "foobar".encode_utf16().collect();
+///     assert_eq!(EXAMPLE.to_vec(), v);
+/// }
+/// ```
+#[macro_export]
+macro_rules! utf16_concat {
+    ($($arg:expr),+ $(,)?) => {{
+        const ARRAY_LENGTH: usize = 0 $(+ $crate::length!($arg))+;
+        const RESULT: [u16; ARRAY_LENGTH] = {
+            let mut data = [0u16; ARRAY_LENGTH];
+            let mut data_index: usize = 0;
+            $({
+                let units: &[u16] = &$crate::utf16!($arg);
+                let mut i = 0;
+                while i < units.len() {
+                    data[data_index] = units[i];
+                    data_index += 1;
+                    i += 1;
+                }
+            })+
+            data
+        };
+        RESULT
+    }};
+}
+
+/// Like [`utf16_concat!`](utf16_concat!), but appends a single trailing
+/// null unit to the joined result.
+///
+/// If you do **not** want a null terminator then you should use
+/// [`utf16_concat!`](utf16_concat!).
+///
+/// ```rust
+/// use utf16_lit::utf16_null_concat;
+///
+/// const EXAMPLE: [u16; 7] = utf16_null_concat!("foo", "bar");
+///
+/// fn main() {
+///     let mut v: Vec<u16> = "foobar".encode_utf16().collect();
+///     v.push(0);
+///     assert_eq!(EXAMPLE.to_vec(), v);
+/// }
+/// ```
+#[macro_export]
+macro_rules! utf16_null_concat {
+    ($($arg:expr),+ $(,)?) => {{
+        const U16: &[u16] = &$crate::utf16_concat!($($arg),+);
         const RESULT: [u16; U16.len() + 1] = {
             let mut data = [0u16; U16.len() + 1];
             let mut i = 0;
@@ -141,3 +673,42 @@ macro_rules! utf16_null {
         RESULT
     }};
 }
+
+/// Repeats the UTF-16 encoding of a string expression `n` times into a
+/// single `[u16]` literal.
+///
+/// ```rust
+/// use utf16_lit::utf16_repeat;
+///
+/// const EXAMPLE: [u16; 4] = utf16_repeat!("ab", 2);
+///
+/// fn main() {
+///     let v: Vec<u16> = "abab".encode_utf16().collect();
+///     assert_eq!(EXAMPLE.to_vec(), v);
+/// }
+/// ```
+#[macro_export]
+macro_rules! utf16_repeat {
+    ($arg:expr, $n:expr) => {{
+        const UNIT_LENGTH: usize = $crate::length!($arg);
+        const REPEAT_COUNT: usize = $n;
+        const ARRAY_LENGTH: usize = UNIT_LENGTH * REPEAT_COUNT;
+        const RESULT: [u16; ARRAY_LENGTH] = {
+            let units: &[u16] = &$crate::utf16!($arg);
+            let mut data = [0u16; ARRAY_LENGTH];
+            let mut data_index: usize = 0;
+            let mut rep: usize = 0;
+            while rep < REPEAT_COUNT {
+                let mut i = 0;
+                while i < UNIT_LENGTH {
+                    data[data_index] = units[i];
+                    data_index += 1;
+                    i += 1;
+                }
+                rep += 1;
+            }
+            data
+        };
+        RESULT
+    }};
+}